@@ -25,6 +25,7 @@ use serde::{de::DeserializeOwned, Serialize};
 use sp_core::{crypto::Pair, sr25519};
 use std::{
     borrow::Borrow,
+    collections::{HashMap, VecDeque},
     fmt::Debug,
     sync::{Arc, MutexGuard},
     time::Duration,
@@ -34,17 +35,317 @@ use tracing::{debug, error, info, trace};
 
 type RpcResult<T> = anyhow::Result<Response<T>, Status>;
 
+/// Default capacity of [`PflixReadCache::bin_added_at`].
+///
+/// One worker rarely talks to more than a handful of distinct pflix
+/// binaries (its own, plus whatever a handover peer happens to be running),
+/// so this comfortably covers the working set without growing unbounded.
+const DEFAULT_READ_CACHE_CAPACITY: usize = 64;
+
+/// A small, bounded read-through cache for lookups that otherwise have to
+/// take `RuntimeState.chain_storage`'s read lock on every RPC call.
+///
+/// Two independent invalidation policies, one per field, rather than a
+/// single blanket one:
+///
+/// - `bin_added_at` holds an on-chain pflix binary's "added at" timestamp,
+///   which `ChainStorage` never changes once recorded. A dispatch can only
+///   add hashes this cache hasn't seen yet (a miss, same cost as today), so
+///   entries already cached stay valid forever and are never invalidated by
+///   a dispatch. Once more than `capacity` hashes are cached, the oldest
+///   inserted ones are evicted first.
+/// - `latest_tip` holds the `(block, timestamp)` pair `current_block()`
+///   derives from `RuntimeState`'s counters, which *does* move forward on
+///   every dispatch. That one is refreshed (not blindly dropped) by
+///   `dispatch_blocks`, since the new value is known right there under the
+///   same lock.
+///
+/// [`RpcService::new_with`] reads its capacity from the worker's own
+/// `args.read_cache_capacity` (falling back to
+/// [`DEFAULT_READ_CACHE_CAPACITY`] if the initial lock can't be taken);
+/// [`RpcService::new_with_cache_capacity`] is still available to override
+/// it explicitly.
+struct PflixReadCache {
+    capacity: usize,
+    bin_added_at: HashMap<[u8; 32], u64>,
+    bin_added_at_order: VecDeque<[u8; 32]>,
+    latest_tip: Option<(BlockNumber, u64)>,
+}
+
+impl PflixReadCache {
+    fn new(capacity: usize) -> Self {
+        PflixReadCache {
+            capacity,
+            bin_added_at: HashMap::new(),
+            bin_added_at_order: VecDeque::new(),
+            latest_tip: None,
+        }
+    }
+
+    fn get_bin_added_at(&self, hash: &[u8; 32]) -> Option<u64> {
+        self.bin_added_at.get(hash).copied()
+    }
+
+    fn insert_bin_added_at(&mut self, hash: [u8; 32], timestamp: u64) {
+        if self.bin_added_at.insert(hash, timestamp).is_none() {
+            self.bin_added_at_order.push_back(hash);
+            while self.bin_added_at_order.len() > self.capacity {
+                if let Some(evicted) = self.bin_added_at_order.pop_front() {
+                    self.bin_added_at.remove(&evicted);
+                }
+            }
+        }
+    }
+
+    fn get_latest_tip(&self) -> Option<(BlockNumber, u64)> {
+        self.latest_tip
+    }
+
+    fn set_latest_tip(&mut self, block: BlockNumber, timestamp: u64) {
+        self.latest_tip = Some((block, timestamp));
+    }
+
+    /// Drop everything. Only correct for a backward state move (see
+    /// `rewind_to_block`): that can un-write storage the cache already
+    /// reflects, which forward dispatch never does.
+    fn clear(&mut self) {
+        self.bin_added_at.clear();
+        self.bin_added_at_order.clear();
+        self.latest_tip = None;
+    }
+}
+
+/// Default bound on a single `get_egress_messages` response, in bytes of
+/// SCALE-encoded `SignedMessage`s. Keeps one call well under a reasonable
+/// extrinsic-weight budget regardless of how much has piled up in the
+/// `MessageSendQueue`.
+const DEFAULT_EGRESS_POOL_MAX_BYTES: usize = 128 * 1024;
+
+/// Bound on how many acked `(origin, sequence)` keys [`EgressPool`] remembers
+/// after they've been dropped from `pending`. Without this, a key that's
+/// been acked but whose underlying message `MessageSendQueue` hasn't purged
+/// yet would look brand new to the next `ingest` and get pooled right back.
+/// FIFO-evicted past this size rather than kept forever; an evicted key can
+/// reappear as pending for one more poll if the queue still hasn't caught up
+/// by then, which is a bounded, self-correcting cost against unbounded growth.
+const EGRESS_POOL_MAX_ACKED_KEYS: usize = 4096;
+
+/// One pending egress message tracked by [`EgressPool`], alongside the
+/// dedup/ack key derived from its origin and sequence number.
+struct EgressPoolEntry {
+    key: (Vec<u8>, u64),
+    origin: pfx_mq::MessageOrigin,
+    message: pfx_mq::SignedMessage,
+}
+
+/// A dedup/priority/ack layer in front of the raw `MessageSendQueue` drain.
+///
+/// `get_egress_messages` used to hand back whatever the queue held,
+/// unbounded and unordered. This pool instead: drops messages that are
+/// already pending or already acked under the same `(origin, sequence)` key
+/// (`ingest` is called on every poll, so the same message would otherwise
+/// get re-queued every time — whether because it's still un-acked, or
+/// because `ack` dropped it from `pending` before `MessageSendQueue` itself
+/// finished draining it), orders the rest with a priority key, and packs
+/// a response bounded by `max_bytes` so a single RPC call can't blow an
+/// extrinsic-weight budget (except that a highest-priority message which
+/// alone exceeds `max_bytes` is still returned, by itself, so it can never
+/// block the pool). Un-acked entries stay pooled across polls
+/// until the relayer confirms on-chain inclusion via `ack`.
+///
+/// The priority key uses the leading byte of the SCALE-encoded
+/// `MessageOrigin` (i.e. its enum variant tag) as a coarse class, then
+/// `sequence` for FIFO order within a class — a deliberately simple stand
+/// in for a real `BindTopic`-aware priority scheme, which would need
+/// access to topic metadata this file doesn't have.
+///
+/// The pool lives on `RpcService` rather than in a checkpointed field, so
+/// a restart currently re-derives it from whatever `MessageSendQueue`
+/// still holds instead of resuming un-acked entries exactly, and forgets
+/// `acked` too (so a message acked just before a restart can come back for
+/// one more round if the queue hadn't purged it yet); true across-restart
+/// survival needs the pool's state folded into `RuntimeState`'s persisted
+/// checkpoint format, which is out of this file's reach.
+struct EgressPool {
+    max_bytes: usize,
+    pending: Vec<EgressPoolEntry>,
+    /// Keys dropped by `ack` but kept around so a not-yet-purged
+    /// `MessageSendQueue` entry doesn't get re-pooled by the next `ingest`.
+    acked: VecDeque<(Vec<u8>, u64)>,
+}
+
+impl EgressPool {
+    fn new(max_bytes: usize) -> Self {
+        EgressPool { max_bytes, pending: Vec::new(), acked: VecDeque::new() }
+    }
+
+    fn entry_key(origin: &pfx_mq::MessageOrigin, sequence: u64) -> (Vec<u8>, u64) {
+        (origin.encode(), sequence)
+    }
+
+    fn priority_key(entry: &EgressPoolEntry) -> (u8, u64) {
+        (entry.key.0.first().copied().unwrap_or(0), entry.key.1)
+    }
+
+    /// Merge freshly-drained messages in, skipping any `(origin, sequence)`
+    /// pair already pooled or already acked (the underlying queue entry may
+    /// still be draining out even though the relayer already confirmed it).
+    fn ingest(&mut self, messages: Vec<(pfx_mq::MessageOrigin, Vec<pfx_mq::SignedMessage>)>) {
+        for (origin, signed_messages) in messages {
+            for message in signed_messages {
+                let key = Self::entry_key(&origin, message.sequence);
+                if self.acked.contains(&key) {
+                    continue;
+                }
+                if self.pending.iter().any(|entry| entry.key == key) {
+                    continue;
+                }
+                self.pending.push(EgressPoolEntry { key, origin, message });
+            }
+        }
+        self.pending.sort_by_key(Self::priority_key);
+    }
+
+    /// Pack the highest-priority un-acked messages into a response bounded
+    /// by `self.max_bytes`, without removing them from the pool. The first
+    /// message is always packed, even when it alone exceeds `self.max_bytes`.
+    fn peek(&self) -> pb::EgressMessages {
+        let mut packed: Vec<(pfx_mq::MessageOrigin, Vec<pfx_mq::SignedMessage>)> = Vec::new();
+        let mut packed_bytes = 0usize;
+        for entry in &self.pending {
+            let message_size = entry.message.encoded_size();
+            if packed_bytes > 0 && packed_bytes + message_size > self.max_bytes {
+                break;
+            }
+            packed_bytes += message_size;
+            match packed.iter_mut().find(|(origin, _)| origin.encode() == entry.origin.encode()) {
+                Some((_, messages)) => messages.push(entry.message.clone()),
+                None => packed.push((entry.origin.clone(), vec![entry.message.clone()])),
+            }
+        }
+        packed
+    }
+
+    /// Drop pooled entries the relayer has confirmed are included on chain,
+    /// and remember their keys so `ingest` doesn't pool them again while
+    /// `MessageSendQueue` still has them queued up.
+    fn ack(&mut self, acked: &[(pfx_mq::MessageOrigin, u64)]) {
+        let acked_keys: Vec<_> = acked.iter().map(|(origin, sequence)| Self::entry_key(origin, *sequence)).collect();
+        self.pending.retain(|entry| !acked_keys.contains(&entry.key));
+        for key in acked_keys {
+            if !self.acked.contains(&key) {
+                self.acked.push_back(key);
+            }
+        }
+        while self.acked.len() > EGRESS_POOL_MAX_ACKED_KEYS {
+            self.acked.pop_front();
+        }
+    }
+}
+
+/// How many previous sealed key versions [`Keystore`] keeps metadata for.
+const KEYSTORE_RETAINED_VERSIONS: usize = 8;
+
+/// Metadata for one worker key version sealed via `handover_receive`.
+///
+/// Mirrors an ethstore-style key directory entry: rather than
+/// `save_runtime_data` overwriting the one sealed identity in place on
+/// every handover, each successfully-sealed key gets its own record here
+/// so a failed or malicious later handover doesn't strand the worker with
+/// no memory of the previously sealed keys.
+#[derive(Clone)]
+struct KeyVersion {
+    version: u64,
+    genesis_block_hash: Vec<u8>,
+    dev_mode: bool,
+    injected: bool,
+    created_at: u64,
+}
+
+/// Bounded audit log of sealed worker-key versions: records are only
+/// appended, and past [`KEYSTORE_RETAINED_VERSIONS`] the oldest are dropped.
+///
+/// `Keystore` doesn't seal, reseal, or roll back anything itself — that
+/// still goes through `Pflix::save_runtime_data`, which lives outside this
+/// file — it only remembers which versions were sealed, in order, and
+/// which one is the most recently sealed, so `list_keys`/`get_key_versions`
+/// have something to report. A record is added only after the
+/// corresponding `save_runtime_data` call has already succeeded, so every
+/// entry here corresponds to a key that was genuinely sealed on disk when
+/// it was recorded; a later handover's `save_runtime_data` replaces that
+/// sealed key, and only the most recent entry matches what is on disk.
+/// Actually rotating back to an earlier sealed version needs a reseal hook
+/// on `Pflix` that this file doesn't have, so this deliberately exposes no
+/// `activate`-style method that would imply it can do that.
+struct Keystore {
+    next_version: u64,
+    active: Option<usize>,
+    versions: VecDeque<KeyVersion>,
+}
+
+impl Keystore {
+    fn new() -> Self {
+        Keystore { next_version: 0, active: None, versions: VecDeque::new() }
+    }
+
+    fn record_activated(&mut self, genesis_block_hash: Vec<u8>, dev_mode: bool, injected: bool, created_at: u64) -> u64 {
+        let version = self.next_version;
+        self.next_version += 1;
+        self.versions.push_back(KeyVersion { version, genesis_block_hash, dev_mode, injected, created_at });
+        while self.versions.len() > KEYSTORE_RETAINED_VERSIONS {
+            self.versions.pop_front();
+        }
+        self.active = Some(self.versions.len() - 1);
+        version
+    }
+
+    fn list(&self) -> Vec<KeyVersion> {
+        self.versions.iter().cloned().collect()
+    }
+
+    fn active_version(&self) -> Option<&KeyVersion> {
+        self.active.and_then(|index| self.versions.get(index))
+    }
+}
+
+/// Throughput numbers from [`Pflix::bench_dispatch_blocks`].
+pub(crate) struct DispatchBenchReport {
+    pub(crate) blocks_dispatched: u64,
+    pub(crate) elapsed: Duration,
+    pub(crate) blocks_per_sec: f64,
+    pub(crate) trie_root_recompute: Duration,
+}
+
 pub struct RpcService<Platform> {
     pub(crate) pfx: PflixSafeBox<Platform>,
+    read_cache: parking_lot::Mutex<PflixReadCache>,
+    egress_pool: parking_lot::Mutex<EgressPool>,
+    keystore: parking_lot::Mutex<Keystore>,
 }
 
 impl<Platform: pal::Platform> RpcService<Platform> {
     pub fn new_with(pfx: PflixSafeBox<Platform>) -> RpcService<Platform> {
-        RpcService { pfx }
+        let read_cache_capacity = pfx
+            .lock(false, false)
+            .map(|guard| guard.args.read_cache_capacity)
+            .unwrap_or(DEFAULT_READ_CACHE_CAPACITY);
+        Self::new_with_cache_capacity(pfx, read_cache_capacity)
     }
 
     pub fn new(platform: Platform) -> RpcService<Platform> {
-        RpcService { pfx: PflixSafeBox::new(platform, None) }
+        Self::new_with(PflixSafeBox::new(platform, None))
+    }
+
+    /// Like [`Self::new_with`], but with an explicit capacity for the
+    /// attestation/timestamp read cache instead of the worker's own
+    /// `args.read_cache_capacity`.
+    pub fn new_with_cache_capacity(pfx: PflixSafeBox<Platform>, read_cache_capacity: usize) -> RpcService<Platform> {
+        RpcService {
+            pfx,
+            read_cache: parking_lot::Mutex::new(PflixReadCache::new(read_cache_capacity)),
+            egress_pool: parking_lot::Mutex::new(EgressPool::new(DEFAULT_EGRESS_POOL_MAX_BYTES)),
+            keystore: parking_lot::Mutex::new(Keystore::new()),
+        }
     }
 }
 
@@ -63,6 +364,10 @@ pub enum PflixServiceError {
 
     #[error("{0}")]
     Anyhow(String),
+
+    /// The request is well-formed but this build has no way to honor it.
+    #[error("unsupported: {0}")]
+    Unsupported(String),
 }
 
 impl From<ScaleDecodeError> for PflixServiceError {
@@ -116,6 +421,17 @@ fn now() -> u64 {
 type PflixResult<T> = anyhow::Result<T, PflixServiceError>;
 
 impl<Platform: pal::Platform> RpcService<Platform> {
+    /// Acquire the single exclusive lock every `PflixApi` handler goes
+    /// through, `dispatch_blocks`/`sync_header`/`init_runtime` included.
+    ///
+    /// `PflixSafeBox` (defined outside this file) exposes only this one
+    /// mutual-exclusion lock today, so a read-only handler that only ever
+    /// touches already-dispatched `RuntimeState.chain_storage` — itself an
+    /// `RwLock` under here, see `cached_bin_added_at` — still has to wait
+    /// out a long `dispatch_blocks` rather than run alongside it. Splitting
+    /// that apart needs `PflixSafeBox` itself to grow a second, shared
+    /// guard over just `RuntimeState`, which is a change at its definition
+    /// site, not something this file can add by itself.
     pub fn lock_pflix(
         &self,
         allow_rcu: bool,
@@ -125,6 +441,41 @@ impl<Platform: pal::Platform> RpcService<Platform> {
     }
 }
 
+impl<Platform: pal::Platform + Serialize + DeserializeOwned> RpcService<Platform> {
+    /// List sealed key versions, most recently sealed last.
+    pub(crate) fn list_keys(&self) -> Vec<KeyVersion> {
+        self.keystore.lock().list()
+    }
+
+    /// Look up the on-chain "added at" timestamp for a pflix binary
+    /// identified by its measurement hash, going through the read cache
+    /// before falling back to `ChainStorage`.
+    fn cached_bin_added_at(&self, pfx: &mut Pflix<Platform>, hash: [u8; 32]) -> Option<u64> {
+        if let Some(timestamp) = self.read_cache.lock().get_bin_added_at(&hash) {
+            return Some(timestamp);
+        }
+        let runtime_state = pfx.runtime_state().ok()?;
+        let timestamp = runtime_state.chain_storage.read().get_pflix_bin_added_at(&hash)?;
+        self.read_cache.lock().insert_bin_added_at(hash, timestamp);
+        Some(timestamp)
+    }
+
+    /// Like `Pflix::current_block`, going through the read cache first.
+    ///
+    /// Unlike `cached_bin_added_at`, the cached value isn't immutable: it's
+    /// kept fresh by `dispatch_blocks` refreshing it under the same lock
+    /// every time state moves forward, rather than by this method comparing
+    /// against anything on a miss.
+    fn cached_current_block(&self, pfx: &mut Pflix<Platform>) -> PflixResult<(BlockNumber, u64)> {
+        if let Some(tip) = self.read_cache.lock().get_latest_tip() {
+            return Ok(tip);
+        }
+        let tip = pfx.current_block()?;
+        self.read_cache.lock().set_latest_tip(tip.0, tip.1);
+        Ok(tip)
+    }
+}
+
 fn create_attestation_report_on<Platform: pal::Platform>(
     platform: &Platform,
     attestation_provider: Option<AttestationProvider>,
@@ -177,20 +528,34 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> PflixApi for RpcSer
         Ok(Response::new(result))
     }
 
+    /// GRANDPA warp-sync: jump from the authority set `LightValidation`
+    /// currently trusts straight to a recent finalized block, verifying only
+    /// the authority-set-transition points instead of replaying every header.
+    async fn warp_sync(&self, request: Request<pb::WarpProof>) -> RpcResult<pb::SyncedTo> {
+        let request = request.into_inner();
+        let fragments = request.decode_fragments().map_err(to_status)?;
+        let result = self.lock_pflix(false, true)?.warp_sync(fragments)?;
+        Ok(Response::new(result))
+    }
+
     /// Dispatch blocks (Sync storage changes)
     async fn dispatch_blocks(&self, request: Request<pb::Blocks>) -> RpcResult<pb::SyncedTo> {
         let request = request.into_inner();
         let blocks = request.decode_blocks().map_err(to_status)?;
-        //FIXME: The RCU lock policy maybe not suitable for pflix,
-        // because the chain storage state in pflix need to share with other service readonly, we don't need a mutex
-        // unnecessary. But adding a long-period lock to the block dispatch process (which can take a long time)
-        // is a bad idea. So there may be a solution:
-        // 1. Use RwLock for the PFLIX instance;
-        // 2. Or refactor pflix to reduce the granularity of PFLIX locks.
-        // However, now in order to avoid cloning the pflix instance (as we do not want to use mutex on its internal
-        // state), we have simply locked it. Remember to optimize here!
-        let synced_to = self.lock_pflix(false, true)?.dispatch_blocks(blocks);
-        Ok(Response::new(synced_to?))
+        // Exclusive lock, same as every other handler — see `lock_pflix`'s
+        // doc comment for why read-only handlers can't run alongside this.
+        let mut pfx = self.lock_pflix(false, true)?;
+        let synced_to = pfx.dispatch_blocks(blocks)?;
+        // `bin_added_at` is append-only storage (see `PflixReadCache`'s doc
+        // comment), so this can only add hashes we haven't cached yet, never
+        // invalidate ones we have — nothing to evict there. The block tip
+        // did move forward though, so refresh it while we still hold the
+        // lock, instead of dropping the whole cache and re-deriving it
+        // lazily on the next read.
+        if let Ok(tip) = pfx.current_block() {
+            self.read_cache.lock().set_latest_tip(tip.0, tip.1);
+        }
+        Ok(Response::new(synced_to))
     }
 
     /// Init the Pflix runtime
@@ -219,15 +584,27 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> PflixApi for RpcSer
         Ok(Response::new(resp))
     }
 
-    /// Get pending egress messages
+    /// Get pending egress messages, deduplicated, priority-ordered, and
+    /// packed under the pool's byte budget. Un-acked messages keep coming
+    /// back on subsequent calls until the relayer confirms inclusion via
+    /// `ack_egress_messages`.
     async fn get_egress_messages(&self, _: Request<()>) -> RpcResult<pb::GetEgressMessagesResponse> {
-        let resp = self
-            .lock_pflix(true, false)?
-            .get_egress_messages()
-            .map(pb::GetEgressMessagesResponse::new)?;
+        let drained = self.lock_pflix(true, false)?.get_egress_messages()?;
+        let mut pool = self.egress_pool.lock();
+        pool.ingest(drained);
+        let resp = pb::GetEgressMessagesResponse::new(pool.peek());
         Ok(Response::new(resp))
     }
 
+    /// Prune egress messages the relayer has confirmed are included on
+    /// chain, so they stop being re-sent by `get_egress_messages`.
+    async fn ack_egress_messages(&self, request: Request<pb::AckEgressMessagesRequest>) -> RpcResult<()> {
+        let request = request.into_inner();
+        let acked = request.decode_acks().map_err(to_status)?;
+        self.egress_pool.lock().ack(&acked);
+        Ok(Response::new(()))
+    }
+
     /// Init the endpoint
     async fn set_endpoint(&self, request: Request<pb::SetEndpointRequest>) -> RpcResult<pb::GetEndpointResponse> {
         let request = request.into_inner();
@@ -258,7 +635,7 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> PflixApi for RpcSer
     /// Key Handover Server: Get challenge for worker key handover from another PFLIX
     async fn handover_create_challenge(&self, _: Request<()>) -> RpcResult<pb::HandoverChallenge> {
         let mut pfx = self.lock_pflix(false, true)?;
-        let (block, ts) = pfx.current_block()?;
+        let (block, ts) = self.cached_current_block(&mut pfx)?;
         let challenge = pfx.get_worker_key_challenge(block, ts);
         Ok(Response::new(pb::HandoverChallenge::new(challenge)))
     }
@@ -274,7 +651,7 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> PflixApi for RpcSer
         let dev_mode = pfx.dev_mode;
         let in_sgx = attestation_provider == Some(AttestationProvider::Ias)
             || attestation_provider == Some(AttestationProvider::Dcap);
-        let (block_number, now_ms) = pfx.current_block()?;
+        let (block_number, now_ms) = self.cached_current_block(&mut pfx)?;
 
         // 1. verify client RA report to ensure it's in sgx
         // this also ensure the message integrity
@@ -337,11 +714,8 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> PflixApi for RpcSer
                 };
                 sgx_fields.measurement_hash()
             };
-            let runtime_state = pfx.runtime_state()?;
-            let my_runtime_timestamp = runtime_state
-                .chain_storage
-                .read()
-                .get_pflix_bin_added_at(&my_runtime_hash)
+            let my_runtime_timestamp = self
+                .cached_bin_added_at(&mut pfx, my_runtime_hash)
                 .ok_or_else(|| from_display("Server pflix not allowed on chain"))?;
 
             let attestation = attestation.ok_or_else(|| from_display("Client attestation not found"))?;
@@ -357,10 +731,8 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> PflixApi for RpcSer
                     sgx_fields.measurement_hash()
                 },
             };
-            let req_runtime_timestamp = runtime_state
-                .chain_storage
-                .read()
-                .get_pflix_bin_added_at(&runtime_hash)
+            let req_runtime_timestamp = self
+                .cached_bin_added_at(&mut pfx, runtime_hash)
                 .ok_or_else(|| from_display("Client pflix not allowed on chain"))?;
 
             if my_runtime_timestamp >= req_runtime_timestamp {
@@ -498,6 +870,8 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> PflixApi for RpcSer
         )
         .map_err(from_debug)?;
 
+        let genesis_block_hash_bytes = encrypted_worker_key.genesis_block_hash.encode();
+
         // only seal if the key is successfully updated
         pfx.save_runtime_data(
             encrypted_worker_key.genesis_block_hash,
@@ -510,12 +884,42 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> PflixApi for RpcSer
         )
         .map_err(from_display)?;
 
+        // `save_runtime_data` above succeeded, so this key is genuinely
+        // sealed on disk now; only after that do we advance the keystore's
+        // active pointer, keeping the previous version's metadata record in
+        // the keystore's list instead of forgetting it the moment we
+        // overwrite it (the list is for auditing; it cannot restore that key).
+        self.keystore.lock().record_activated(genesis_block_hash_bytes, dev_mode, false, now());
+
         // clear cached RA report and handover ecdh key to prevent replay
         pfx.runtime_info = None;
         pfx.handover_ecdh_key = None;
         Ok(Response::new(()))
     }
 
+    /// Enumerate sealed worker-key versions and their metadata, for
+    /// operational tooling that wants to audit past handovers.
+    ///
+    /// List-only: see [`Keystore`]'s doc comment for why rolling the active
+    /// key back to an earlier listed version isn't something this RPC (or
+    /// anything else in this file) can do today.
+    async fn get_key_versions(&self, _: Request<()>) -> RpcResult<pb::KeyVersions> {
+        let keystore = self.keystore.lock();
+        let active = keystore.active_version().map(|k| k.version);
+        let versions = keystore
+            .list()
+            .into_iter()
+            .map(|k| pb::KeyVersion {
+                version: k.version,
+                genesis_block_hash: k.genesis_block_hash,
+                dev_mode: k.dev_mode,
+                injected: k.injected,
+                created_at: k.created_at,
+            })
+            .collect();
+        Ok(Response::new(pb::KeyVersions { versions, active }))
+    }
+
     /// Load given chain state into the pflix
     async fn load_chain_state(&self, request: Request<pb::ChainState>) -> RpcResult<()> {
         let request = request.into_inner();
@@ -543,6 +947,20 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> PflixApi for RpcSer
         let synced_to = self.lock_pflix(false, false)?.take_checkpoint().map_err(from_debug)?;
         Ok(Response::new(pb::SyncedTo { synced_to }))
     }
+
+    /// Roll `chain_storage`/`storage_synchronizer` back to an earlier block,
+    /// for recovering when a non-finalized block turns out to be on a
+    /// discarded fork. See `Pflix::rewind_to_block` for why this currently
+    /// always refuses rather than actually rewinding.
+    async fn rewind_to_block(&self, request: Request<pb::RewindToBlock>) -> RpcResult<pb::SyncedTo> {
+        let target = request.into_inner().target;
+        let result = self.lock_pflix(false, true)?.rewind_to_block(target)?;
+        // State moved backwards, so cached forward-looking reads are no
+        // longer trustworthy (see `PflixReadCache::clear`); unlike the tip
+        // refresh in `dispatch_blocks`, the whole cache is dropped here.
+        self.read_cache.lock().clear();
+        Ok(Response::new(result))
+    }
 }
 
 impl<Platform: pal::Platform + Serialize + DeserializeOwned> Pflix<Platform> {
@@ -556,6 +974,41 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> Pflix<Platform> {
         self.system.as_mut().ok_or_else(|| from_display("Runtime not initialized"))
     }
 
+    /// Roll state back to `target`, for recovering when a non-finalized
+    /// block turns out to be on a discarded fork.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `target` is at or past the next block to dispatch.
+    /// Otherwise always errors with [`PflixServiceError::Unsupported`]:
+    /// `chain_storage` and `storage_synchronizer` only expose forward
+    /// primitives (`feed_block`, `sync_header`, `warp_sync`) plus
+    /// whole-state checkpointing (`take_checkpoint`/`load_chain_state`).
+    /// Neither a retained reverse-delta log nor per-block state roots to
+    /// verify a rewind against exists here today, so actually rolling
+    /// `chain_storage`/`storage_synchronizer`/`System` back to an arbitrary
+    /// `target` isn't something this build can do safely. The real recovery
+    /// path today is to re-supply state at or before `target` via
+    /// `load_chain_state` and re-dispatch forward from there.
+    pub(crate) fn rewind_to_block(&mut self, target: BlockNumber) -> PflixResult<pb::SyncedTo> {
+        trace!(target, "rewind_to_block");
+        let counters = self.runtime_state()?.storage_synchronizer.counters();
+        if target >= counters.next_block_number {
+            return Err(from_display("rewind_to_block target must be strictly before the next dispatched block"));
+        }
+        // The fallback this suggests (re-supply state via `load_chain_state`)
+        // isn't always available — `can_load_chain_state` is the same check
+        // `load_chain_state` itself would make — so tell the caller which
+        // situation they're in instead of pointing at a path that might
+        // turn out to be a dead end too.
+        let fallback_available = self.can_load_chain_state();
+        Err(PflixServiceError::Unsupported(format!(
+            "rewind_to_block({target}) is not supported: no reverse-delta log is retained. \
+             load_chain_state with a state exported at or before {target} is {}",
+            if fallback_available { "available as a recovery path right now" } else { "not usable right now either" }
+        )))
+    }
+
     pub(crate) fn current_block(&mut self) -> PflixResult<(BlockNumber, u64)> {
         let now_ms = self.runtime_state()?.chain_storage.read().timestamp_now();
         let block = self
@@ -633,6 +1086,53 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> Pflix<Platform> {
         Ok(pb::SyncedTo { synced_to: last_header })
     }
 
+    /// Warp-sync `LightValidation` past every authority-set rotation carried
+    /// by `fragments`, landing on the block finalized by the last one, so a
+    /// freshly launched worker can skip straight to near-tip by replaying
+    /// only the one justified header per rotation instead of every header
+    /// in between.
+    pub(crate) fn warp_sync(&mut self, fragments: Vec<blocks::WarpSyncFragment>) -> PflixResult<pb::SyncedTo> {
+        trace!(fragments = fragments.len(), "warp_sync");
+        let counters = self.runtime_state()?.storage_synchronizer.counters();
+        if counters.next_block_number > 0 {
+            return Err(from_display("warp_sync can only run before any block has been dispatched"));
+        }
+        if fragments.is_empty() {
+            return Err(from_display("warp_sync requires at least one fragment"));
+        }
+        // Defense in depth: reject a fragment sequence whose set ids aren't
+        // strictly contiguous before any of it reaches the synchronizer, so
+        // a relay can't skip an intervening authority-set change by omitting
+        // its fragment.
+        for pair in fragments.windows(2) {
+            let [a, b] = pair else { unreachable!("windows(2) always yields length-2 slices") };
+            if b.set_id != a.set_id + 1 {
+                return Err(from_display(format!(
+                    "warp_sync fragments must cover contiguous authority sets, got set_id {} right after {}",
+                    b.set_id, a.set_id
+                )));
+            }
+        }
+        // `StorageSynchronizer` has no dedicated warp-sync entry point: it
+        // only knows how to verify and apply one justified header (plus the
+        // authority-set change it carries) at a time via `sync_header`. A
+        // warp fragment is exactly that — the header that justifies one
+        // authority-set transition — so warp-syncing is just replaying
+        // `sync_header` once per fragment instead of once per block; the
+        // >= 2/3 justification-weight check against the currently trusted
+        // authority set still happens inside `sync_header` on every call.
+        let mut last_header = counters.next_header_number;
+        for fragment in fragments {
+            last_header = self
+                .runtime_state()?
+                .storage_synchronizer
+                .sync_header(vec![fragment.header], Some(fragment.authority_set_change))
+                .map_err(from_display)?;
+        }
+
+        Ok(pb::SyncedTo { synced_to: last_header })
+    }
+
     pub(crate) fn dispatch_blocks(
         &mut self,
         mut blocks: Vec<blocks::BlockHeaderWithChanges>,
@@ -681,7 +1181,53 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> Pflix<Platform> {
         Ok(pb::SyncedTo { synced_to: last_block })
     }
 
-    //Check whether checkpoint file is used and save it regularly
+    /// Drive `dispatch_blocks` against an already-prepared batch and report
+    /// structured throughput numbers for the storage-synchronizer/message
+    /// dispatch hot path.
+    ///
+    /// NOTE: this is the measurable piece, not the harness asked for — a
+    /// CLI subcommand needs a `main`/bin target to register against, and
+    /// this crate's snapshot has no bin target at all (`pflix_service.rs`
+    /// is its only source file), so there's nothing to wire a subcommand
+    /// into here. Building a throwaway `RuntimeState` from a captured
+    /// genesis plus a recorded block range, and running with checkpointing
+    /// disabled, is left to whatever embeds this: call this method instead
+    /// of `dispatch_blocks` directly on a `RuntimeState` set up that way.
+    pub(crate) fn bench_dispatch_blocks(
+        &mut self,
+        blocks: Vec<blocks::BlockHeaderWithChanges>,
+    ) -> PflixResult<DispatchBenchReport> {
+        use std::time::Instant;
+        // Mirror `dispatch_blocks`'s own retain predicate rather than
+        // `blocks.len()`: a fixture that (re-)includes already-applied
+        // blocks would otherwise inflate `blocks_dispatched`/`blocks_per_sec`
+        // relative to what actually got dispatched.
+        let next_block_number = self.runtime_state()?.storage_synchronizer.counters().next_block_number;
+        let blocks_dispatched =
+            blocks.iter().filter(|b| b.block_header.number >= next_block_number).count() as u64;
+        let dispatch_started = Instant::now();
+        self.dispatch_blocks(blocks)?;
+        let elapsed = dispatch_started.elapsed();
+        let root_recompute_started = Instant::now();
+        let _state_root = self.runtime_state()?.chain_storage.read().root();
+        let trie_root_recompute = root_recompute_started.elapsed();
+        let blocks_per_sec =
+            if elapsed.as_secs_f64() > 0.0 { blocks_dispatched as f64 / elapsed.as_secs_f64() } else { f64::INFINITY };
+        Ok(DispatchBenchReport { blocks_dispatched, elapsed, blocks_per_sec, trie_root_recompute })
+    }
+
+    // Check whether checkpoint file is used and save it regularly.
+    //
+    // NOTE: this still takes a full `ChainStorage` snapshot every
+    // `checkpoint_interval` seconds rather than a per-block delta, so data
+    // loss is bounded by the interval rather than by one block, and a large
+    // trie makes each snapshot expensive. Turning this into id-sequenced
+    // delta persistence (one compact trie-diff + `state_root` per dispatched
+    // block, folded into an occasional full checkpoint, with recovery
+    // replaying deltas past the last checkpoint's id and verifying the
+    // final root) needs a new on-disk format and a delta log writer that
+    // both live in the checkpoint/storage layer `Pflix`/`RuntimeState` sit
+    // on top of — outside what this file defines, so it isn't done here.
     fn maybe_take_checkpoint(&mut self) -> anyhow::Result<()> {
         if !self.args.enable_checkpoint {
             return Ok(());
@@ -689,7 +1235,13 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> Pflix<Platform> {
         if self.last_checkpoint.elapsed().as_secs() < self.args.checkpoint_interval {
             return Ok(());
         }
+        // No delta log to size this against (see the note above), so at
+        // least make the one cost the doc comment warns about visible:
+        // how long a full snapshot actually takes against the live trie.
+        use std::time::Instant;
+        let started = Instant::now();
         self.take_checkpoint()?;
+        debug!(elapsed = ?started.elapsed(), "took a full checkpoint snapshot");
         Ok(())
     }
 
@@ -914,7 +1466,7 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> Pflix<Platform> {
         Ok(cached_resp.clone())
     }
 
-    fn get_egress_messages(&mut self) -> PflixResult<pb::EgressMessages> {
+    fn get_egress_messages(&self) -> PflixResult<pb::EgressMessages> {
         use pfx_mq::{MessageOrigin, SignedMessage};
         let messages: Vec<(MessageOrigin, Vec<SignedMessage>)> = self
             .runtime_state
@@ -1121,3 +1673,49 @@ impl<Platform: pal::Platform + Serialize + DeserializeOwned> Pflix<Platform> {
         return self.handover_last_challenge.take().as_ref() == Some(challenge);
     }
 }
+
+/// In-process driver for fuzzing the decode-and-apply ingestion paths:
+/// `dispatch_blocks`, `sync_header`, and `load_storage_proof`, the last of
+/// which feeds attacker-influenceable proof nodes straight into
+/// `chain_storage.inner_mut().load_proof`.
+///
+/// A full `cargo fuzz` setup also wants its own `fuzz/` crate with
+/// `arbitrary`-derived input types, honggfuzz target registration, and a
+/// seed corpus — that needs its own manifest, and this tree has no
+/// Cargo.toml anywhere to hang a `[workspace]` member off of. What's
+/// addressable from this file is the reusable driver such a target would
+/// call against a `Pflix` built the same way `init_runtime` builds one:
+/// run one untrusted input through the matching entry point and assert it
+/// never panics, regardless of what `PflixResult` it returns.
+#[cfg(fuzzing)]
+pub mod fuzz {
+    use super::*;
+
+    /// Fuzz target body for `load_storage_proof`. Only reaches the proof
+    /// loading path when `pfx` was constructed with `safe_mode_level >= 2`;
+    /// below that `load_storage_proof` returns an error right away. This
+    /// only drives the method and does not itself change `safe_mode_level`.
+    pub fn fuzz_load_storage_proof<Platform: pal::Platform + Serialize + DeserializeOwned>(
+        pfx: &mut Pflix<Platform>,
+        proof: Vec<Vec<u8>>,
+    ) {
+        let _ = pfx.load_storage_proof(proof);
+    }
+
+    /// Fuzz target body for `sync_header`.
+    pub fn fuzz_sync_header<Platform: pal::Platform + Serialize + DeserializeOwned>(
+        pfx: &mut Pflix<Platform>,
+        headers: Vec<blocks::HeaderToSync>,
+        authority_set_change: Option<blocks::AuthoritySetChange>,
+    ) {
+        let _ = pfx.sync_header(headers, authority_set_change);
+    }
+
+    /// Fuzz target body for `dispatch_blocks`.
+    pub fn fuzz_dispatch_blocks<Platform: pal::Platform + Serialize + DeserializeOwned>(
+        pfx: &mut Pflix<Platform>,
+        blocks: Vec<blocks::BlockHeaderWithChanges>,
+    ) {
+        let _ = pfx.dispatch_blocks(blocks);
+    }
+}
@@ -12,6 +12,8 @@ use itertools::Itertools;
 use num_bigint::BigUint;
 use rand::{Rng, RngCore, SeedableRng};
 use rand_chacha::ChaCha8Rng;
+use sha2::{Digest, Sha256};
+use subtle::{Choice, ConstantTimeEq};
 extern crate alloc;
 use alloc::borrow::ToOwned;
 use alloc::boxed::Box;
@@ -23,13 +25,49 @@ use zeroize::Zeroizing;
 use zeroize_derive::{Zeroize, ZeroizeOnDrop};
 
 /// Secret key for the BFV encryption scheme.
-#[derive(Debug, PartialEq, Eq, Clone, Zeroize, ZeroizeOnDrop)]
+#[derive(Debug, Clone, Zeroize, ZeroizeOnDrop)]
 pub struct SecretKey {
     #[zeroize(skip)]
     pub(crate) par: Arc<BfvParameters>,
     pub(crate) coeffs: Box<[i64]>,
+    /// The master seed this key was deterministically derived from, if it
+    /// was created via [`SecretKey::from_seed`].
+    seed: Option<[u8; 32]>,
 }
 
+impl ConstantTimeEq for SecretKey {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // The parameters and coefficient count are public bookkeeping (not
+        // secret material), so it's safe to use them to short-circuit. The
+        // derivation seed, in contrast, is itself secret material (it
+        // regenerates the exact same key via `from_seed`/`derive_child`, and
+        // is zeroized alongside `coeffs`), so it must be folded in via
+        // `ct_eq` rather than compared with `!=`.
+        if self.par != other.par || self.coeffs.len() != other.coeffs.len() {
+            return Choice::from(0);
+        }
+        let seed_eq = match (&self.seed, &other.seed) {
+            (Some(a), Some(b)) => a.ct_eq(b),
+            (None, None) => Choice::from(1),
+            _ => Choice::from(0),
+        };
+        let coeffs_eq = self
+            .coeffs
+            .iter()
+            .zip(other.coeffs.iter())
+            .fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b));
+        seed_eq & coeffs_eq
+    }
+}
+
+impl PartialEq for SecretKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SecretKey {}
+
 impl SecretKey {
     /// Generate a random [`SecretKey`].
     pub fn random<R: RngCore>(par: &Arc<BfvParameters>, rng: &mut R) -> Self {
@@ -42,9 +80,41 @@ impl SecretKey {
         Self {
             par: par.to_owned(),
             coeffs: coeffs.into_boxed_slice(),
+            seed: None,
         }
     }
 
+    /// Deterministically (re)generates a [`SecretKey`] from a 32-byte master
+    /// seed, so a single backed-up seed can regenerate an entire keyset
+    /// after loss without ever storing raw coefficients.
+    pub fn from_seed(seed: [u8; 32], par: &Arc<BfvParameters>) -> Self {
+        let mut rng = ChaCha8Rng::from_seed(seed);
+        let coeffs = sample_vec_cbd(par.degree(), par.variance, &mut rng).unwrap();
+        Self { par: par.to_owned(), coeffs: coeffs.into_boxed_slice(), seed: Some(seed) }
+    }
+
+    /// Derives the `index`-th child key from this key's master seed.
+    ///
+    /// The child seed is obtained by fast-forwarding a `ChaCha8` stream
+    /// keyed with the master seed to a position derived from `index`, giving
+    /// a domain-separated, reproducible seed for each index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if this key was not created via
+    /// [`SecretKey::from_seed`] (and so has no master seed to derive from).
+    pub fn derive_child(&self, index: u64) -> Result<Self> {
+        let seed = self
+            .seed
+            .ok_or_else(|| Error::DefaultError("derive_child requires a key created via SecretKey::from_seed".to_string()))?;
+        let mut stream = ChaCha8Rng::from_seed(seed);
+        // Each index gets its own, non-overlapping window of the stream.
+        stream.set_word_pos((index as u128 + 1) * 1024);
+        let mut child_seed = <ChaCha8Rng as SeedableRng>::Seed::default();
+        stream.fill_bytes(&mut child_seed);
+        Ok(Self::from_seed(child_seed, &self.par))
+    }
+
     /// Measure the noise in a [`Ciphertext`].
     ///
     /// # Safety
@@ -129,6 +199,639 @@ impl SecretKey {
             level,
         })
     }
+
+    /// Decrypt `ct`, like [`try_decrypt`](FheDecrypter::try_decrypt), but
+    /// guaranteeing that the secret-power loop and scaling run in time
+    /// independent of the noise magnitude and of the coefficient values.
+    ///
+    /// This is the side-channel-hardened default for production
+    /// deployments; [`measure_noise`](Self::measure_noise) and the plain
+    /// `try_decrypt` remain opt-in variable-time fast paths.
+    pub fn try_decrypt_constant_time(&self, ct: &Ciphertext) -> Result<Plaintext> {
+        if self.par != ct.par {
+            return Err(Error::DefaultError("Incompatible BFV parameters".to_string()));
+        }
+
+        // Let's create a secret key with the ciphertext context
+        let mut s = Zeroizing::new(Poly::try_convert_from(
+            self.coeffs.as_ref(),
+            ct[0].ctx(),
+            false,
+            Representation::PowerBasis,
+        )?);
+        s.change_representation(Representation::Ntt);
+        let mut si = s.clone();
+
+        let mut c = Zeroizing::new(ct[0].clone());
+        c.disallow_variable_time_computations();
+
+        // Compute the phase c0 + c1*s + c2*s^2 + ... where the secret power
+        // s^k is computed on-the-fly. Unlike `try_decrypt`, `si` is always
+        // advanced, even on the last iteration, so the work done does not
+        // depend on anything but the (public) ciphertext length.
+        for i in 1..ct.len() {
+            let mut cis = Zeroizing::new(ct[i].clone());
+            cis.disallow_variable_time_computations();
+            *cis.as_mut() *= si.as_ref();
+            *c.as_mut() += &cis;
+            *si.as_mut() *= s.as_ref();
+        }
+        c.change_representation(Representation::PowerBasis);
+
+        let d = Zeroizing::new(c.scale(&self.par.scalers[ct.level])?);
+
+        // TODO: Can we handle plaintext moduli that are BigUint?
+        let v = Zeroizing::new(
+            Vec::<u64>::from(d.as_ref())
+                .iter_mut()
+                .map(|vi| *vi + *self.par.plaintext)
+                .collect_vec(),
+        );
+        let mut w = v[..self.par.degree()].to_vec();
+        let q = Modulus::new(self.par.moduli[0]).map_err(Error::MathError)?;
+        q.reduce_vec(&mut w);
+        self.par.plaintext.reduce_vec(&mut w);
+
+        let mut poly = Poly::try_convert_from(&w, ct[0].ctx(), false, Representation::PowerBasis)?;
+        poly.change_representation(Representation::Ntt);
+
+        Ok(Plaintext {
+            par: self.par.clone(),
+            value: w.into_boxed_slice(),
+            encoding: None,
+            poly_ntt: poly,
+            level: ct.level,
+        })
+    }
+}
+
+/// A single party's share of a [`SecretKey`] produced by
+/// [`SecretKey::share_shamir`].
+///
+/// For `t >= 2`, fewer than `t` shares reveal nothing about the underlying
+/// key (with `t == 1` every share equals the key reduced mod `moduli[0]`);
+/// any `t` of the `n` shares can be combined (see [`combine`]) to decrypt a
+/// [`Ciphertext`] without ever reconstructing the key.
+#[derive(Debug, Clone, Zeroize, ZeroizeOnDrop)]
+pub struct SecretKeyShare {
+    #[zeroize(skip)]
+    par: Arc<BfvParameters>,
+    /// The evaluation point `i` in `1..=n` this share corresponds to.
+    index: usize,
+    coeffs: Box<[u64]>,
+    /// Blinding factor backing this share's published [`ShareCommitment`].
+    ///
+    /// An exponent in the ~2048-bit commitment group (see
+    /// [`commitment_modulus`]), not the ambient ciphertext modulus.
+    /// `num_bigint::BigUint` doesn't implement [`Zeroize`], so unlike
+    /// `coeffs` this field isn't wiped on drop.
+    #[zeroize(skip)]
+    blinding: BigUint,
+}
+
+/// One party's contribution to a threshold decryption, produced by
+/// [`SecretKeyShare::partial_decrypt`] and consumed by [`combine`].
+#[derive(Debug, Clone)]
+pub struct PartialDecryption {
+    index: usize,
+    c0: Poly,
+    masked: Poly,
+    level: usize,
+}
+
+/// Adds `a + b` modulo `q`.
+fn mod_add(a: u64, b: u64, q: u64) -> u64 {
+    (((a as u128) + (b as u128)) % (q as u128)) as u64
+}
+
+/// Multiplies `a * b` modulo `q`.
+fn mod_mul(a: u64, b: u64, q: u64) -> u64 {
+    (((a as u128) * (b as u128)) % (q as u128)) as u64
+}
+
+/// Computes the modular inverse of `a` modulo `q` via the extended Euclidean
+/// algorithm. Panics if `a` is not invertible mod `q`.
+fn mod_inverse(a: u64, q: u64) -> u64 {
+    let (mut old_r, mut r) = (a as i128, q as i128);
+    let (mut old_s, mut s) = (1i128, 0i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        let (new_r, new_s) = (old_r - quotient * r, old_s - quotient * s);
+        old_r = r;
+        r = new_r;
+        old_s = s;
+        s = new_s;
+    }
+    assert_eq!(old_r, 1, "value is not invertible modulo q");
+    old_s.rem_euclid(q as i128) as u64
+}
+
+/// Computes the Lagrange coefficient for party `i` at `x = 0`, given the set
+/// of participating indices, modulo `q`.
+fn lagrange_coefficient(i: usize, indices: &[usize], q: u64) -> u64 {
+    let mut num = 1u64;
+    let mut den = 1u64;
+    for &j in indices {
+        if j == i {
+            continue;
+        }
+        num = mod_mul(num, j as u64 % q, q);
+        let diff = ((j as i128 - i as i128).rem_euclid(q as i128)) as u64;
+        den = mod_mul(den, diff, q);
+    }
+    mod_mul(num, mod_inverse(den, q), q)
+}
+
+impl SecretKey {
+    /// Splits this key into `n` Shamir shares such that any `t` of them can
+    /// jointly decrypt a [`Ciphertext`] (see [`SecretKeyShare::partial_decrypt`]
+    /// and [`combine`]), without any party ever learning the key itself.
+    ///
+    /// For each of the `degree()` coefficients of the key (lifted into `Z_q`
+    /// for the ciphertext modulus `moduli[0]`), a degree-`t-1` polynomial is
+    /// sampled with that coefficient as its constant term and evaluated at
+    /// points `1..=n`; share `i` holds the vector of evaluations at `i`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the threshold doesn't satisfy `1 <= t <= n`.
+    ///
+    /// Threshold sharing only supports `BfvParameters` with a single
+    /// ciphertext modulus (`par.moduli.len() == 1`): shares and partial
+    /// decryptions are reduced mod `moduli[0]` alone, which would be an
+    /// incomplete (and likely wrong) lift of the secret across an RNS basis
+    /// with more than one prime. Returns an error for multi-modulus
+    /// parameters rather than silently mis-lifting the key.
+    pub fn share_shamir<R: RngCore>(&self, n: usize, t: usize, rng: &mut R) -> Result<Vec<SecretKeyShare>> {
+        if t < 1 || t > n {
+            return Err(Error::DefaultError("threshold must satisfy 1 <= t <= n".to_string()));
+        }
+        if self.par.moduli.len() != 1 {
+            return Err(Error::DefaultError(
+                "Threshold secret sharing only supports single-modulus BfvParameters".to_string(),
+            ));
+        }
+        let q = self.par.moduli[0];
+
+        let mut evaluations = vec![vec![0u64; self.par.degree()]; n];
+        for (ci, &coeff) in self.coeffs.iter().enumerate() {
+            let constant = coeff.rem_euclid(q as i64) as u64;
+            let mut poly_coeffs = Vec::with_capacity(t);
+            poly_coeffs.push(constant);
+            for _ in 1..t {
+                poly_coeffs.push(rng.gen_range(0..q));
+            }
+            for (i, row) in evaluations.iter_mut().enumerate() {
+                let x = (i + 1) as u64;
+                // Horner's method.
+                let mut acc = 0u64;
+                for &c in poly_coeffs.iter().rev() {
+                    acc = mod_add(mod_mul(acc, x, q), c, q);
+                }
+                row[ci] = acc;
+            }
+        }
+
+        let subgroup_order = commitment_subgroup_order(&commitment_modulus());
+        Ok(evaluations
+            .into_iter()
+            .enumerate()
+            .map(|(i, coeffs)| SecretKeyShare {
+                par: self.par.clone(),
+                index: i + 1,
+                coeffs: coeffs.into_boxed_slice(),
+                blinding: random_biguint_below(rng, &subgroup_order),
+            })
+            .collect())
+    }
+}
+
+impl SecretKeyShare {
+    /// Computes this party's contribution to a threshold decryption of `ct`,
+    /// together with a [`PartialDecryptionProof`] that an untrusted
+    /// aggregator can check with [`verify_partial`] before [`combine`]-ing.
+    ///
+    /// A fresh "smudging" error, sampled with the given (larger than the
+    /// scheme's encryption) variance, is added to hide this share's
+    /// individual noise term from whoever combines the partials.
+    ///
+    /// # Errors
+    ///
+    /// Like [`SecretKey::share_shamir`], this only supports ciphertexts
+    /// under single-modulus `BfvParameters`.
+    pub fn partial_decrypt<R: RngCore>(
+        &self,
+        ct: &Ciphertext,
+        smudging_variance: usize,
+        rng: &mut R,
+    ) -> Result<(PartialDecryption, PartialDecryptionProof)> {
+        if self.par != ct.par {
+            return Err(Error::DefaultError("Incompatible BFV parameters".to_string()));
+        }
+        if self.par.moduli.len() != 1 {
+            return Err(Error::DefaultError(
+                "Threshold partial decryption only supports single-modulus BfvParameters".to_string(),
+            ));
+        }
+        let mut s = Zeroizing::new(Poly::try_convert_from(
+            self.coeffs.as_ref(),
+            ct[1].ctx(),
+            false,
+            Representation::PowerBasis,
+        )?);
+        s.change_representation(Representation::Ntt);
+
+        let mut c = Zeroizing::new(ct[1].clone());
+        c.disallow_variable_time_computations();
+        *c.as_mut() *= s.as_ref();
+
+        let smudging =
+            Poly::small(ct[1].ctx(), Representation::Ntt, smudging_variance, rng).map_err(Error::MathError)?;
+        *c.as_mut() += &smudging;
+
+        let mut masked = (*c).clone();
+        unsafe {
+            masked.allow_variable_time_computations();
+        }
+        masked.change_representation(Representation::PowerBasis);
+
+        let partial = PartialDecryption { index: self.index, c0: ct[0].clone(), masked, level: ct.level };
+        let proof = self.prove_partial(&partial, &smudging, rng);
+        Ok((partial, proof))
+    }
+
+    /// Publishes a Pedersen commitment to this share, binding it to the
+    /// party's public index without revealing any of its coefficients.
+    /// Meant to be published once, at sharing time, so that later
+    /// [`PartialDecryption`]s can be checked against it via
+    /// [`verify_partial`].
+    ///
+    /// Computed as `g^y * h^blinding mod P` in the order-`Q` subgroup of
+    /// `Z_P^*` (see [`commitment_modulus`]), not as a linear combination
+    /// over the BFV ciphertext modulus: binding this commitment requires
+    /// solving a discrete log mod the 2048-bit `P`, rather than just
+    /// inverting a public scalar mod the (much smaller, and otherwise
+    /// ordinary) BFV modulus `q`.
+    pub fn commitment(&self) -> ShareCommitment {
+        let modulus = commitment_modulus();
+        let (g, h) = commitment_generators(&modulus);
+        let y = BigUint::from(share_fingerprint(&self.coeffs, self.index, self.par.moduli[0]));
+        let commitment = (g.modpow(&y, &modulus) * h.modpow(&self.blinding, &modulus)) % &modulus;
+        ShareCommitment { index: self.index, commitment }
+    }
+
+    /// Produces a sigma proof, Fiat-Shamir-bound to `partial`, that the
+    /// committed `y = fingerprint(self.coeffs)` is the same fingerprint an
+    /// aggregator would recompute from `partial.masked` itself (see
+    /// [`verify_partial`]) — i.e. that `partial` was really derived from
+    /// the committed share, rather than just proving knowledge of *some*
+    /// opening of this share's [`ShareCommitment`] unrelated to `partial`.
+    ///
+    /// `smudging` is the same (NTT-representation) noise `partial_decrypt`
+    /// added to `masked`; it's revealed here so the aggregator can undo it
+    /// (dividing out the public `ct1`) to recompute that fingerprint.
+    fn prove_partial<R: RngCore>(
+        &self,
+        partial: &PartialDecryption,
+        smudging: &Poly,
+        rng: &mut R,
+    ) -> PartialDecryptionProof {
+        let modulus = commitment_modulus();
+        let subgroup_order = commitment_subgroup_order(&modulus);
+        let (_g, h) = commitment_generators(&modulus);
+        let smudging = Vec::<u64>::from(smudging);
+
+        let rho = random_biguint_below(rng, &subgroup_order);
+        let t = h.modpow(&rho, &modulus);
+
+        let commitment = self.commitment();
+        let e = fiat_shamir_challenge(&commitment, &t, partial, &smudging, &subgroup_order);
+
+        let z = (&rho + &e * &self.blinding) % &subgroup_order;
+        PartialDecryptionProof { t, z, smudging }
+    }
+}
+
+/// Public commitment to a [`SecretKeyShare`], published once at sharing
+/// time via [`SecretKeyShare::commitment`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareCommitment {
+    index: usize,
+    commitment: BigUint,
+}
+
+/// A sigma proof, Fiat-Shamir-bound to a specific [`PartialDecryption`],
+/// that it was computed from the share behind a published
+/// [`ShareCommitment`].
+///
+/// Unlike a plain proof of knowledge of the commitment's opening, this
+/// binds the committed share to `partial.masked` itself: [`verify_partial`]
+/// undoes the (here-revealed) `smudging` and the public `ct1` to recompute,
+/// from `masked` alone, the Schwartz-Zippel fingerprint the share *would*
+/// need to have to produce it, and checks the commitment opens to exactly
+/// that value. A party that submits a `masked` it didn't honestly derive
+/// from its committed share — even while reusing its own real `blinding` —
+/// can't produce a passing proof, since it would need the recomputed
+/// fingerprint to land on the one value already fixed by its publication.
+///
+/// This still isn't a full succinct proof over the ring multiplication:
+/// `smudging` is revealed, not range-proven, so a party willing to search
+/// for a same-fingerprint-but-wrong `smudging` within the bound
+/// [`verify_partial`] checks could still force an accept at a cost growing
+/// with the gap between that bound and the BFV modulus — closing that
+/// fully would need a genuine range proof over `smudging`, which this
+/// module doesn't implement.
+#[derive(Debug, Clone)]
+pub struct PartialDecryptionProof {
+    t: BigUint,
+    z: BigUint,
+    /// The smudging error `partial_decrypt` added, in NTT representation.
+    smudging: Vec<u64>,
+}
+
+/// Verifies that `partial` was computed from the share behind `commitment`
+/// for ciphertext `ct`, letting an untrusted aggregator reject malformed or
+/// malicious partial decryptions before calling [`combine`]. `smudging_variance`
+/// must be the same bound agreed on for [`SecretKeyShare::partial_decrypt`].
+pub fn verify_partial(
+    partial: &PartialDecryption,
+    proof: &PartialDecryptionProof,
+    commitment: &ShareCommitment,
+    ct: &Ciphertext,
+    smudging_variance: usize,
+) -> bool {
+    if partial.index != commitment.index {
+        return false;
+    }
+    if ct.par.moduli.len() != 1 {
+        return false;
+    }
+    let q = ct.par.moduli[0];
+    let degree = ct.par.degree();
+    if proof.smudging.len() != degree {
+        return false;
+    }
+
+    // `masked = ct1 * s_i + smudging` is computed pointwise in NTT
+    // representation, so `s_i = (masked - smudging) * ct1^{-1}`,
+    // coordinate by coordinate.
+    let ct1_coeffs = Vec::<u64>::from(&ct[1]);
+    let mut masked_ntt = partial.masked.clone();
+    masked_ntt.change_representation(Representation::Ntt);
+    let masked_coeffs = Vec::<u64>::from(&masked_ntt);
+
+    let mut reconstructed_ntt = vec![0u64; degree];
+    for k in 0..degree {
+        if ct1_coeffs[k] == 0 {
+            return false;
+        }
+        let diff = mod_add(masked_coeffs[k], q - proof.smudging[k] % q, q);
+        reconstructed_ntt[k] = mod_mul(diff, mod_inverse(ct1_coeffs[k], q), q);
+    }
+    let Ok(mut reconstructed) = Poly::try_convert_from(&reconstructed_ntt, ct[1].ctx(), false, Representation::Ntt)
+    else {
+        return false;
+    };
+    reconstructed.change_representation(Representation::PowerBasis);
+    let y_target = BigUint::from(share_fingerprint(
+        &Vec::<u64>::from(&reconstructed),
+        partial.index,
+        q,
+    ));
+
+    // Sanity-bound the revealed noise against the agreed smudging variance:
+    // not a formal range proof (see [`PartialDecryptionProof`]'s doc), but
+    // cheap insurance against a wildly out-of-range forged `smudging`.
+    let Ok(mut smudging_poly) = Poly::try_convert_from(&proof.smudging, ct[1].ctx(), false, Representation::Ntt)
+    else {
+        return false;
+    };
+    smudging_poly.change_representation(Representation::PowerBasis);
+    let bound = 64u64.saturating_mul(smudging_variance as u64).max(1);
+    for v in Vec::<u64>::from(&smudging_poly) {
+        let centered = if v > q / 2 { v as i64 - q as i64 } else { v as i64 };
+        if centered.unsigned_abs() > bound {
+            return false;
+        }
+    }
+
+    let modulus = commitment_modulus();
+    let subgroup_order = commitment_subgroup_order(&modulus);
+    let (g, h) = commitment_generators(&modulus);
+    let e = fiat_shamir_challenge(commitment, &proof.t, partial, &proof.smudging, &subgroup_order);
+
+    let g_y_inv = g
+        .modpow(&y_target, &modulus)
+        .modpow(&(&modulus - BigUint::from(2u32)), &modulus);
+    let target = (&commitment.commitment * &g_y_inv) % &modulus;
+
+    let lhs = h.modpow(&proof.z, &modulus);
+    let rhs = (&proof.t * target.modpow(&e, &modulus)) % &modulus;
+    lhs == rhs
+}
+
+/// RFC 3526 MODP Group 14: a standard, publicly verifiable 2048-bit safe
+/// prime (`P = 2*Q + 1` with `Q` itself prime), long used for
+/// Diffie-Hellman and reused here as the modulus of a real
+/// discrete-log-hard multiplicative-group Pedersen commitment. Using this
+/// in place of a linear combination over the (much smaller, and otherwise
+/// ordinary) BFV ciphertext modulus is what makes [`SecretKeyShare::commitment`]
+/// binding.
+const COMMITMENT_PRIME_HEX: &str = concat!(
+    "FFFFFFFF", "FFFFFFFF", "C90FDAA2", "2168C234", "C4C6628B", "80DC1CD1",
+    "29024E08", "8A67CC74", "020BBEA6", "3B139B22", "514A0879", "8E3404DD",
+    "EF9519B3", "CD3A431B", "302B0A6D", "F25F1437", "4FE1356D", "6D51C245",
+    "E485B576", "625E7EC6", "F44C42E9", "A637ED6B", "0BFF5CB6", "F406B7ED",
+    "EE386BFB", "5A899FA5", "AE9F2411", "7C4B1FE6", "49286651", "ECE45B3D",
+    "C2007CB8", "A163BF05", "98DA4836", "1C55D39A", "69163FA8", "FD24CF5F",
+    "83655D23", "DCA3AD96", "1C62F356", "208552BB", "9ED52907", "7096966D",
+    "670C354E", "4ABC9804", "F1746C08", "CA18217C", "32905E46", "2E36CE3B",
+    "E39E772C", "180E8603", "9B2783A2", "EC07A28F", "B5C55DF0", "6F4C52C9",
+    "DE2BCBF6", "95581718", "3995497C", "EA956AE5", "15D22618", "98FA0510",
+    "15728E5A", "8AACAA68", "FFFFFFFF", "FFFFFFFF",
+);
+
+/// Parses [`COMMITMENT_PRIME_HEX`] into the commitment group's modulus `P`.
+fn commitment_modulus() -> BigUint {
+    BigUint::parse_bytes(COMMITMENT_PRIME_HEX.as_bytes(), 16)
+        .expect("COMMITMENT_PRIME_HEX is a valid hex literal")
+}
+
+/// The order `Q = (P - 1) / 2` of the quadratic-residue subgroup of
+/// `Z_P^*` that commitments, blinding factors and sigma-proof exponents
+/// all live in.
+fn commitment_subgroup_order(modulus: &BigUint) -> BigUint {
+    (modulus - BigUint::from(1u32)) / BigUint::from(2u32)
+}
+
+/// Derives a public, reproducible generator of the order-`Q` subgroup from
+/// a domain-separation label: hash the label into a `ChaCha8` stream,
+/// reduce its output mod `P`, then square mod `P` (any square mod a safe
+/// prime lands in the order-`Q` subgroup). Retries on the
+/// vanishingly-unlikely degenerate outputs 0 and 1.
+fn hash_to_generator(label: &[u8], modulus: &BigUint) -> BigUint {
+    let seed: [u8; 32] = Sha256::digest(label).into();
+    let mut rng = ChaCha8Rng::from_seed(seed);
+    let byte_len = (modulus.bits() as usize + 7) / 8;
+    loop {
+        let mut buf = vec![0u8; byte_len];
+        rng.fill_bytes(&mut buf);
+        let candidate = BigUint::from_bytes_be(&buf) % modulus;
+        let g = candidate.modpow(&BigUint::from(2u32), modulus);
+        if g > BigUint::from(1u32) {
+            return g;
+        }
+    }
+}
+
+/// Fixed, nothing-up-my-sleeve Pedersen commitment generators, as two
+/// independently domain-separated calls to [`hash_to_generator`] — their
+/// discrete-log relation to each other is unknown to everyone, which is
+/// what the commitment's binding property relies on.
+fn commitment_generators(modulus: &BigUint) -> (BigUint, BigUint) {
+    (
+        hash_to_generator(b"fhe/bfv/threshold/pedersen-g", modulus),
+        hash_to_generator(b"fhe/bfv/threshold/pedersen-h", modulus),
+    )
+}
+
+/// Samples a uniform [`BigUint`] in `[0, bound)` by rejection sampling
+/// fixed-width output from `rng`.
+fn random_biguint_below<R: RngCore>(rng: &mut R, bound: &BigUint) -> BigUint {
+    let bits = bound.bits() as usize;
+    let byte_len = (bits + 7) / 8;
+    let excess_bits = byte_len * 8 - bits;
+    loop {
+        let mut buf = vec![0u8; byte_len];
+        rng.fill_bytes(&mut buf);
+        if excess_bits > 0 {
+            buf[0] &= 0xffu8 >> excess_bits;
+        }
+        let candidate = BigUint::from_bytes_be(&buf);
+        if candidate < *bound {
+            return candidate;
+        }
+    }
+}
+
+/// Expands `bytes` into enough pseudorandom output (via `SHA-256` in
+/// counter mode) to sample fairly below `modulus`. `SHA-256`'s
+/// collision-resistance and unpredictability are what make the
+/// Fiat-Shamir challenges derived from this (see [`fiat_shamir_challenge`])
+/// sound; a non-cryptographic hash would not provide either.
+fn hash_to_biguint(bytes: &[u8], modulus: &BigUint) -> BigUint {
+    let byte_len = (modulus.bits() as usize + 7) / 8;
+    let mut out = Vec::with_capacity(byte_len + 32);
+    let mut counter: u64 = 0;
+    while out.len() < byte_len {
+        let mut block = bytes.to_vec();
+        block.extend_from_slice(&counter.to_le_bytes());
+        out.extend_from_slice(&Sha256::digest(&block));
+        counter += 1;
+    }
+    out.truncate(byte_len);
+    BigUint::from_bytes_le(&out) % modulus
+}
+
+/// Deterministically derives the public random vector used to fold a
+/// share's coefficients into a single `Z_q` scalar (a Schwartz-Zippel
+/// fingerprint), from the share's public index.
+fn share_challenge_vector(index: usize, degree: usize, q: u64) -> Vec<u64> {
+    let mut label = b"fhe/bfv/threshold/share-fingerprint".to_vec();
+    label.extend_from_slice(&(index as u64).to_le_bytes());
+    let seed: [u8; 32] = Sha256::digest(&label).into();
+    let mut rng = ChaCha8Rng::from_seed(seed);
+    (0..degree).map(|_| rng.gen_range(0..q)).collect()
+}
+
+fn share_fingerprint(coeffs: &[u64], index: usize, q: u64) -> u64 {
+    share_challenge_vector(index, coeffs.len(), q)
+        .iter()
+        .zip(coeffs.iter())
+        .fold(0u64, |acc, (r, c)| mod_add(acc, mod_mul(*r, *c, q), q))
+}
+
+/// Computes the Fiat-Shamir challenge binding a sigma-proof commitment `t`
+/// to the published `commitment`, the specific `partial` it proves
+/// knowledge for, and the revealed `smudging` error, as an element of
+/// `Z_{subgroup_order}`. Folding `smudging` in here stops a prover from
+/// picking it after seeing the challenge.
+fn fiat_shamir_challenge(
+    commitment: &ShareCommitment,
+    t: &BigUint,
+    partial: &PartialDecryption,
+    smudging: &[u64],
+    subgroup_order: &BigUint,
+) -> BigUint {
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&commitment.commitment.to_bytes_be());
+    bytes.extend_from_slice(&t.to_bytes_be());
+    for c in Vec::<u64>::from(&partial.masked) {
+        bytes.extend_from_slice(&c.to_le_bytes());
+    }
+    for c in Vec::<u64>::from(&partial.c0) {
+        bytes.extend_from_slice(&c.to_le_bytes());
+    }
+    for c in smudging {
+        bytes.extend_from_slice(&c.to_le_bytes());
+    }
+    hash_to_biguint(&bytes, subgroup_order)
+}
+
+/// Combines any `t` valid [`PartialDecryption`]s produced by distinct shares
+/// of the same [`SecretKey::share_shamir`] split into the decrypted
+/// [`Plaintext`].
+///
+/// Each partial is scaled by the integer Lagrange coefficient for its index
+/// (computed modulo the ciphertext modulus `moduli[0]`), the results are
+/// summed together with `c0`, and the existing `scale`/`reduce_vec`/
+/// plaintext-reduction rounding pipeline from [`try_decrypt`](FheDecrypter::try_decrypt)
+/// is applied. Combining any `t` valid partials yields the same result as a
+/// non-threshold decryption, up to the smudging noise added by each party.
+pub fn combine(partials: &[PartialDecryption], par: &Arc<BfvParameters>) -> Result<Plaintext> {
+    let Some(first) = partials.first() else {
+        return Err(Error::DefaultError("No partial decryptions to combine".to_string()));
+    };
+    if par.moduli.len() != 1 {
+        return Err(Error::DefaultError(
+            "Threshold combine only supports single-modulus BfvParameters".to_string(),
+        ));
+    }
+    let level = first.level;
+    let ctx = first.c0.ctx();
+    let degree = par.degree();
+    let q = par.moduli[0];
+    let indices = partials.iter().map(|p| p.index).collect_vec();
+
+    let mut acc = vec![0u64; degree];
+    for p in partials {
+        let lambda = lagrange_coefficient(p.index, &indices, q);
+        for (a, c) in acc.iter_mut().zip(Vec::<u64>::from(&p.masked).iter()) {
+            *a = mod_add(*a, mod_mul(lambda, *c, q), q);
+        }
+    }
+    // `c0` is kept in the ciphertext's NTT representation, while `masked`
+    // and `acc` are in power basis.
+    let mut c0 = first.c0.clone();
+    c0.change_representation(Representation::PowerBasis);
+    for (a, c) in acc.iter_mut().zip(Vec::<u64>::from(&c0).iter()) {
+        *a = mod_add(*a, *c, q);
+    }
+
+    let c = Poly::try_convert_from(&acc, ctx, false, Representation::PowerBasis)?;
+    let d = Zeroizing::new(c.scale(&par.scalers[level])?);
+
+    // TODO: Can we handle plaintext moduli that are BigUint?
+    let v = Zeroizing::new(Vec::<u64>::from(d.as_ref()).iter_mut().map(|vi| *vi + *par.plaintext).collect_vec());
+    let mut w = v[..degree].to_vec();
+    let qm = Modulus::new(q).map_err(Error::MathError)?;
+    qm.reduce_vec(&mut w);
+    par.plaintext.reduce_vec(&mut w);
+
+    let mut poly = Poly::try_convert_from(&w, ctx, false, Representation::PowerBasis)?;
+    poly.change_representation(Representation::Ntt);
+
+    Ok(Plaintext { par: par.clone(), value: w.into_boxed_slice(), encoding: None, poly_ntt: poly, level })
 }
 
 impl FheParametrized for SecretKey {
@@ -146,6 +849,17 @@ impl Serialize for SecretKey {
             bytes.extend_from_slice(&coeff.to_le_bytes());
         }
 
+        // Append the master seed, if any, as a one-byte presence flag
+        // followed by the 32 seed bytes, so a `from_seed` key keeps working
+        // with `derive_child` after a serialize/deserialize round trip.
+        match self.seed {
+            Some(seed) => {
+                bytes.push(1);
+                bytes.extend_from_slice(&seed);
+            }
+            None => bytes.push(0),
+        }
+
         bytes
     }
 }
@@ -189,13 +903,154 @@ impl DeserializeParametrized for SecretKey {
             cursor += 8;
         }
 
+        // Deserialize the optional master seed, if this key was created via
+        // `SecretKey::from_seed`. Older byte strings without the trailing
+        // flag (written before this field existed) are treated as seedless.
+        let seed = match bytes.get(cursor) {
+            Some(1) => {
+                cursor += 1;
+                if bytes.len() < cursor + 32 {
+                    return Err(Error::DefaultError(
+                        "Invalid byte length for SecretKey deserialization".to_string(),
+                    ));
+                }
+                let mut seed = [0u8; 32];
+                seed.copy_from_slice(&bytes[cursor..cursor + 32]);
+                Some(seed)
+            }
+            _ => None,
+        };
+
         // Return the deserialized SecretKey with the externally provided parameters
         Ok(Self {
             par: par.clone(),
             coeffs: coeffs.into_boxed_slice(),
+            seed,
         })
     }
 }
+
+/// Lays out the little-endian coefficient bytes followed by the optional
+/// master seed (a one-byte presence flag plus 32 seed bytes), mirroring the
+/// layout of [`Serialize::to_bytes`] minus the length prefix (the length is
+/// recovered from `BfvParameters::degree()` on the way back in). The result
+/// is raw bytes; [`SecretKey`]'s `serde::Serialize` hex-encodes it for
+/// human-readable formats.
+#[cfg(feature = "serde")]
+fn coeffs_to_bytes(coeffs: &[i64], seed: Option<[u8; 32]>) -> Vec<u8> {
+    let mut bytes: Vec<u8> = coeffs.iter().flat_map(|c| c.to_le_bytes()).collect();
+    match seed {
+        Some(seed) => {
+            bytes.push(1);
+            bytes.extend_from_slice(&seed);
+        }
+        None => bytes.push(0),
+    }
+    bytes
+}
+
+/// `serde` support for [`SecretKey`], following the same human-readable vs.
+/// compact split as `secp256k1`'s `SecretKey`: a hex string for
+/// human-readable formats (JSON, YAML), and otherwise the same bytes written
+/// with `serialize_bytes`, a length-prefixed byte string rather than a
+/// fixed-width tuple. [`SecretKeyDeserializer`] reads the compact form back
+/// as a `Vec<u8>`.
+///
+/// Only [`serde::Serialize`] is implemented directly: `serde::Deserialize`
+/// cannot be, since the wire format carries no [`BfvParameters`] to validate
+/// against. Use [`SecretKeyDeserializer`] instead, the same way
+/// [`DeserializeParametrized::from_bytes`] needs `par` passed in explicitly.
+#[cfg(feature = "serde")]
+impl serde::Serialize for SecretKey {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let bytes = coeffs_to_bytes(&self.coeffs, self.seed);
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&hex::encode(bytes))
+        } else {
+            serializer.serialize_bytes(&bytes)
+        }
+    }
+}
+
+/// A [`serde::de::DeserializeSeed`] that deserializes a [`SecretKey`] by
+/// carrying the [`Arc<BfvParameters>`] the bare wire format cannot encode,
+/// and validates the decoded coefficient count against `par.degree()`.
+#[cfg(feature = "serde")]
+pub struct SecretKeyDeserializer<'a> {
+    par: &'a Arc<BfvParameters>,
+}
+
+#[cfg(feature = "serde")]
+impl<'a> SecretKeyDeserializer<'a> {
+    /// Creates a new deserializer seed backed by `par`.
+    pub fn new(par: &'a Arc<BfvParameters>) -> Self {
+        Self { par }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, 'a> serde::de::DeserializeSeed<'de> for SecretKeyDeserializer<'a> {
+    type Value = SecretKey;
+
+    fn deserialize<D>(self, deserializer: D) -> core::result::Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let bytes = if deserializer.is_human_readable() {
+            let s = <alloc::string::String as serde::Deserialize>::deserialize(deserializer)?;
+            hex::decode(s).map_err(serde::de::Error::custom)?
+        } else {
+            <Vec<u8> as serde::Deserialize>::deserialize(deserializer)?
+        };
+
+        let coeffs_len = self.par.degree() * 8;
+        if bytes.len() < coeffs_len + 1 {
+            return Err(serde::de::Error::custom(
+                "Invalid byte length for SecretKey deserialization",
+            ));
+        }
+
+        let coeffs = bytes[..coeffs_len]
+            .chunks_exact(8)
+            .map(|c| i64::from_le_bytes(c.try_into().expect("chunk is 8 bytes")))
+            .collect_vec();
+
+        // Decode the optional master seed trailing the coefficients, the
+        // same layout `to_bytes`/`from_bytes` use, so a `from_seed` key keeps
+        // working with `derive_child` after a serde round trip.
+        let seed = match bytes.get(coeffs_len) {
+            Some(1) => {
+                if bytes.len() != coeffs_len + 1 + 32 {
+                    return Err(serde::de::Error::custom(
+                        "Invalid byte length for SecretKey deserialization",
+                    ));
+                }
+                let mut seed = [0u8; 32];
+                seed.copy_from_slice(&bytes[coeffs_len + 1..coeffs_len + 1 + 32]);
+                Some(seed)
+            }
+            Some(0) => {
+                if bytes.len() != coeffs_len + 1 {
+                    return Err(serde::de::Error::custom(
+                        "Invalid byte length for SecretKey deserialization",
+                    ));
+                }
+                None
+            }
+            _ => {
+                return Err(serde::de::Error::custom(
+                    "Invalid byte length for SecretKey deserialization",
+                ))
+            }
+        };
+
+        Ok(SecretKey { par: self.par.clone(), coeffs: coeffs.into_boxed_slice(), seed })
+    }
+}
+
 impl FheEncrypter<Plaintext, Ciphertext> for SecretKey {
     type Error = Error;
 
@@ -211,73 +1066,70 @@ impl FheDecrypter<Plaintext, Ciphertext> for SecretKey {
 
     fn try_decrypt(&self, ct: &Ciphertext) -> Result<Plaintext> {
         if self.par != ct.par {
-            Err(Error::DefaultError(
-                "Incompatible BFV parameters".to_string(),
-            ))
-        } else {
-            // Let's create a secret key with the ciphertext context
-            let mut s = Zeroizing::new(Poly::try_convert_from(
-                self.coeffs.as_ref(),
-                ct[0].ctx(),
-                false,
-                Representation::PowerBasis,
-            )?);
-            s.change_representation(Representation::Ntt);
-            let mut si = s.clone();
-
-            let mut c = Zeroizing::new(ct[0].clone());
-            c.disallow_variable_time_computations();
-
-            // Compute the phase c0 + c1*s + c2*s^2 + ... where the secret power
-            // s^k is computed on-the-fly
-            for i in 1..ct.len() {
-                let mut cis = Zeroizing::new(ct[i].clone());
-                cis.disallow_variable_time_computations();
-                *cis.as_mut() *= si.as_ref();
-                *c.as_mut() += &cis;
-                if i + 1 < ct.len() {
-                    *si.as_mut() *= s.as_ref();
-                }
-            }
-            c.change_representation(Representation::PowerBasis);
-
-            let d = Zeroizing::new(c.scale(&self.par.scalers[ct.level])?);
-
-            // TODO: Can we handle plaintext moduli that are BigUint?
-            let v = Zeroizing::new(
-                Vec::<u64>::from(d.as_ref())
-                    .iter_mut()
-                    .map(|vi| *vi + *self.par.plaintext)
-                    .collect_vec(),
-            );
-            let mut w = v[..self.par.degree()].to_vec();
-            let q = Modulus::new(self.par.moduli[0]).map_err(Error::MathError)?;
-            q.reduce_vec(&mut w);
-            self.par.plaintext.reduce_vec(&mut w);
-
-            let mut poly =
-                Poly::try_convert_from(&w, ct[0].ctx(), false, Representation::PowerBasis)?;
-            poly.change_representation(Representation::Ntt);
-
-            let pt = Plaintext {
-                par: self.par.clone(),
-                value: w.into_boxed_slice(),
-                encoding: None,
-                poly_ntt: poly,
-                level: ct.level,
-            };
+            return Err(Error::DefaultError("Incompatible BFV parameters".to_string()));
+        }
 
-            Ok(pt)
+        // Let's create a secret key with the ciphertext context
+        let mut s = Zeroizing::new(Poly::try_convert_from(
+            self.coeffs.as_ref(),
+            ct[0].ctx(),
+            false,
+            Representation::PowerBasis,
+        )?);
+        s.change_representation(Representation::Ntt);
+        let mut si = s.clone();
+
+        let mut c = Zeroizing::new(ct[0].clone());
+        c.disallow_variable_time_computations();
+
+        // Compute the phase c0 + c1*s + c2*s^2 + ... where the secret power
+        // s^k is computed on-the-fly
+        for i in 1..ct.len() {
+            let mut cis = Zeroizing::new(ct[i].clone());
+            cis.disallow_variable_time_computations();
+            *cis.as_mut() *= si.as_ref();
+            *c.as_mut() += &cis;
+            if i + 1 < ct.len() {
+                *si.as_mut() *= s.as_ref();
+            }
         }
+        c.change_representation(Representation::PowerBasis);
+
+        let d = Zeroizing::new(c.scale(&self.par.scalers[ct.level])?);
+
+        // TODO: Can we handle plaintext moduli that are BigUint?
+        let v = Zeroizing::new(
+            Vec::<u64>::from(d.as_ref())
+                .iter_mut()
+                .map(|vi| *vi + *self.par.plaintext)
+                .collect_vec(),
+        );
+        let mut w = v[..self.par.degree()].to_vec();
+        let q = Modulus::new(self.par.moduli[0]).map_err(Error::MathError)?;
+        q.reduce_vec(&mut w);
+        self.par.plaintext.reduce_vec(&mut w);
+
+        let mut poly = Poly::try_convert_from(&w, ct[0].ctx(), false, Representation::PowerBasis)?;
+        poly.change_representation(Representation::Ntt);
+
+        let pt = Plaintext {
+            par: self.par.clone(),
+            value: w.into_boxed_slice(),
+            encoding: None,
+            poly_ntt: poly,
+            level: ct.level,
+        };
+
+        Ok(pt)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::SecretKey;
+    use super::{combine, verify_partial, SecretKey};
     use crate::bfv::{parameters::BfvParameters, Encoding, Plaintext};
     use crate::Error;
-    use fhe_traits::{FheDecrypter, FheEncoder, FheEncrypter};
+    use fhe_traits::{DeserializeParametrized, FheDecrypter, FheEncoder, FheEncrypter, Serialize};
     use rand::thread_rng;
 
     #[test]
@@ -320,4 +1172,223 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn threshold_share_combine_roundtrip() -> Result<(), Error> {
+        let mut rng = thread_rng();
+        let params = BfvParameters::default_arc(1, 16);
+        let sk = SecretKey::random(&params, &mut rng);
+        let shares = sk.share_shamir(5, 3, &mut rng)?;
+        let commitments: Vec<_> = shares.iter().map(|share| share.commitment()).collect();
+
+        let pt = Plaintext::try_encode(
+            &params.plaintext.random_vec(params.degree(), &mut rng),
+            Encoding::poly_at_level(0),
+            &params,
+        )?;
+        let ct = sk.try_encrypt(&pt, &mut rng)?;
+
+        // Any `t` of the `n` shares should be able to reconstruct the
+        // plaintext, without ever reconstructing `sk`.
+        let mut partials = Vec::new();
+        for (share, commitment) in shares.iter().zip(commitments.iter()).take(3) {
+            let (partial, proof) = share.partial_decrypt(&ct, params.variance, &mut rng)?;
+            assert!(verify_partial(&partial, &proof, commitment, &ct, params.variance));
+            partials.push(partial);
+        }
+        let combined = combine(&partials, &params)?;
+        assert_eq!(combined, pt);
+
+        Ok(())
+    }
+
+    // Covers admi-n/polkadot-hackathon-2024#chunk0-5 (commitments and sigma
+    // proofs for verifiable partial decryptions).
+    #[test]
+    fn verify_partial_rejects_tampered_partial() -> Result<(), Error> {
+        let mut rng = thread_rng();
+        let params = BfvParameters::default_arc(1, 16);
+        let sk = SecretKey::random(&params, &mut rng);
+        let shares = sk.share_shamir(5, 3, &mut rng)?;
+        let commitment = shares[0].commitment();
+
+        let pt = Plaintext::try_encode(
+            &params.plaintext.random_vec(params.degree(), &mut rng),
+            Encoding::poly_at_level(0),
+            &params,
+        )?;
+        let ct = sk.try_encrypt(&pt, &mut rng)?;
+        let (partial, proof) = shares[0].partial_decrypt(&ct, params.variance, &mut rng)?;
+        assert!(verify_partial(&partial, &proof, &commitment, &ct, params.variance));
+
+        // A proof produced by a different party's share must not verify
+        // against this commitment.
+        let (other_partial, other_proof) = shares[1].partial_decrypt(&ct, params.variance, &mut rng)?;
+        assert!(!verify_partial(&other_partial, &other_proof, &commitment, &ct, params.variance));
+
+        // Swapping in another party's commitment for the same partial must
+        // also fail.
+        let other_commitment = shares[1].commitment();
+        assert!(!verify_partial(&partial, &proof, &other_commitment, &ct, params.variance));
+
+        Ok(())
+    }
+
+    // Covers admi-n/polkadot-hackathon-2024#chunk0-5 (the proof must bind
+    // `masked` itself, not just prove knowledge of the commitment's
+    // opening): a party can't reuse its own real share/blinding to produce
+    // a passing proof for a `masked` it fabricated instead of deriving
+    // honestly from that share.
+    #[test]
+    fn verify_partial_rejects_forged_masked_from_the_same_party() -> Result<(), Error> {
+        let mut rng = thread_rng();
+        let params = BfvParameters::default_arc(1, 16);
+        let sk = SecretKey::random(&params, &mut rng);
+        let shares = sk.share_shamir(5, 3, &mut rng)?;
+        let commitment = shares[0].commitment();
+
+        let pt = Plaintext::try_encode(
+            &params.plaintext.random_vec(params.degree(), &mut rng),
+            Encoding::poly_at_level(0),
+            &params,
+        )?;
+        let ct = sk.try_encrypt(&pt, &mut rng)?;
+        let (mut partial, proof) = shares[0].partial_decrypt(&ct, params.variance, &mut rng)?;
+
+        // The party now swaps in an arbitrary `masked`, reusing the honest
+        // proof generated for its real partial decryption.
+        let (other_partial, _) = shares[1].partial_decrypt(&ct, params.variance, &mut rng)?;
+        partial.masked = other_partial.masked;
+        assert!(!verify_partial(&partial, &proof, &commitment, &ct, params.variance));
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_decrypt_rejects_mismatched_parameters() -> Result<(), Error> {
+        let mut rng = thread_rng();
+        let params = BfvParameters::default_arc(1, 16);
+        let other_params = BfvParameters::default_arc(1, 8);
+
+        let sk = SecretKey::random(&params, &mut rng);
+        let shares = sk.share_shamir(5, 3, &mut rng)?;
+
+        let other_sk = SecretKey::random(&other_params, &mut rng);
+        let pt = Plaintext::try_encode(
+            &other_params.plaintext.random_vec(other_params.degree(), &mut rng),
+            Encoding::poly_at_level(0),
+            &other_params,
+        )?;
+        let ct = other_sk.try_encrypt(&pt, &mut rng)?;
+
+        assert!(shares[0]
+            .partial_decrypt(&ct, params.variance, &mut rng)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_bytes_from_bytes_roundtrip() {
+        let mut rng = thread_rng();
+        let params = BfvParameters::default_arc(1, 16);
+        let sk = SecretKey::random(&params, &mut rng);
+        let bytes = sk.to_bytes();
+        let sk2 = SecretKey::from_bytes(&bytes, &params).unwrap();
+        assert_eq!(sk.coeffs, sk2.coeffs);
+    }
+
+    // Covers admi-n/polkadot-hackathon-2024#chunk0-4 (deterministic
+    // hierarchical key derivation from a master seed).
+    #[test]
+    fn to_bytes_from_bytes_roundtrip_preserves_seed() {
+        let params = BfvParameters::default_arc(1, 16);
+        let sk = SecretKey::from_seed([5u8; 32], &params);
+        let bytes = sk.to_bytes();
+        let sk2 = SecretKey::from_bytes(&bytes, &params).unwrap();
+        assert_eq!(sk, sk2);
+        assert_eq!(sk.derive_child(0).unwrap(), sk2.derive_child(0).unwrap());
+    }
+
+    // Covers admi-n/polkadot-hackathon-2024#chunk0-2 (serde
+    // Serialize/Deserialize support for SecretKey).
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        use super::SecretKeyDeserializer;
+        use serde::de::DeserializeSeed;
+
+        let mut rng = thread_rng();
+        let params = BfvParameters::default_arc(1, 16);
+        let sk = SecretKey::random(&params, &mut rng);
+
+        // Human-readable (hex) path.
+        let json = serde_json::to_string(&sk).unwrap();
+        let sk2 = SecretKeyDeserializer::new(&params)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        assert_eq!(sk, sk2);
+    }
+
+    // Covers admi-n/polkadot-hackathon-2024#chunk0-2 (serde round trip) and
+    // admi-n/polkadot-hackathon-2024#chunk0-4 (seed survives it).
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip_preserves_seed() {
+        use super::SecretKeyDeserializer;
+        use serde::de::DeserializeSeed;
+
+        let params = BfvParameters::default_arc(1, 16);
+        let sk = SecretKey::from_seed([11u8; 32], &params);
+
+        let json = serde_json::to_string(&sk).unwrap();
+        let sk2 = SecretKeyDeserializer::new(&params)
+            .deserialize(&mut serde_json::Deserializer::from_str(&json))
+            .unwrap();
+        assert_eq!(sk, sk2);
+        assert_eq!(sk.derive_child(0).unwrap(), sk2.derive_child(0).unwrap());
+    }
+
+    // Covers admi-n/polkadot-hackathon-2024#chunk0-4 (deterministic
+    // hierarchical key derivation from a master seed).
+    #[test]
+    fn derive_child_is_deterministic() {
+        let params = BfvParameters::default_arc(1, 16);
+        let seed = [7u8; 32];
+        let sk = SecretKey::from_seed(seed, &params);
+
+        let child_a = sk.derive_child(0).unwrap();
+        let child_b = sk.derive_child(0).unwrap();
+        assert_eq!(child_a, child_b);
+
+        let other_child = sk.derive_child(1).unwrap();
+        assert_ne!(child_a, other_child);
+    }
+
+    // Covers admi-n/polkadot-hackathon-2024#chunk0-4 (deterministic
+    // hierarchical key derivation from a master seed).
+    #[test]
+    fn derive_child_errors_without_seed() {
+        let mut rng = thread_rng();
+        let params = BfvParameters::default_arc(1, 16);
+        let sk = SecretKey::random(&params, &mut rng);
+        assert!(sk.derive_child(0).is_err());
+    }
+
+    // Covers admi-n/polkadot-hackathon-2024#chunk0-3 (constant-time
+    // comparison for SecretKey).
+    #[test]
+    fn ct_eq_matches_partial_eq() {
+        let params = BfvParameters::default_arc(1, 16);
+        let seed = [3u8; 32];
+        let sk = SecretKey::from_seed(seed, &params);
+        let same = SecretKey::from_seed(seed, &params);
+        assert_eq!(sk, same);
+
+        let other = SecretKey::from_seed([9u8; 32], &params);
+        assert_ne!(sk, other);
+
+        let no_seed = SecretKey::new(sk.coeffs.to_vec(), &params);
+        assert_ne!(sk, no_seed);
+    }
 }
\ No newline at end of file